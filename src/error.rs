@@ -0,0 +1,69 @@
+//! Error and Result types used throughout this crate.
+
+use std::fmt;
+
+/// Alias for a `Result` with the error type fixed to [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Errors that can happen while sending or managing a notification.
+#[derive(Debug)]
+pub enum Error {
+    /// A `zbus` call failed.
+    #[cfg(all(unix, not(target_os = "macos")))]
+    Zbus(zbus::Error),
+
+    /// The message returned by the bus could not be decoded into the expected type.
+    #[cfg(all(unix, not(target_os = "macos")))]
+    ZVariant(zvariant::Error),
+
+    /// An I/O error. Failures while reading an image file are reported as `Image` instead.
+    Io(std::io::Error),
+
+    /// An image could not be decoded, e.g. because [`ImageData::open`](crate::ImageData::open)
+    /// was pointed at an unsupported format or corrupt data.
+    #[cfg(feature = "images")]
+    Image(image::ImageError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(all(unix, not(target_os = "macos")))]
+            Error::Zbus(e) => write!(f, "zbus error: {}", e),
+            #[cfg(all(unix, not(target_os = "macos")))]
+            Error::ZVariant(e) => write!(f, "zvariant error: {}", e),
+            Error::Io(e) => write!(f, "io error: {}", e),
+            #[cfg(feature = "images")]
+            Error::Image(e) => write!(f, "image decode error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+impl From<zbus::Error> for Error {
+    fn from(error: zbus::Error) -> Error {
+        Error::Zbus(error)
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+impl From<zvariant::Error> for Error {
+    fn from(error: zvariant::Error) -> Error {
+        Error::ZVariant(error)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Error {
+        Error::Io(error)
+    }
+}
+
+#[cfg(feature = "images")]
+impl From<image::ImageError> for Error {
+    fn from(error: image::ImageError) -> Error {
+        Error::Image(error)
+    }
+}
@@ -0,0 +1,295 @@
+//! Hints as described at
+//! [https://developer.gnome.org/notification-spec/#hints](https://developer.gnome.org/notification-spec/#hints)
+
+use std::collections::HashMap;
+
+use crate::{image::ImageData, urgency::Urgency};
+
+/// All currently implemented `NotificationHints` that can be sent.
+///
+/// As listed at
+/// [https://developer.gnome.org/notification-spec/#hints](https://developer.gnome.org/notification-spec/#hints)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Hint {
+    /// This is a reverse-DNS string used for application-specific purposes.
+    DesktopEntry(String),
+
+    /// A raw image, as opposed to an icon referenced by name or path.
+    ImageData(ImageData),
+
+    /// When set the server will not automatically remove the notification when an action has
+    /// been invoked.
+    Resident(bool),
+
+    /// The path to a sound file to play when the notification pops up.
+    SoundFile(String),
+
+    /// A themeable named sound from the freedesktop.org sound naming specification to play when
+    /// the notification pops up.
+    SoundName(String),
+
+    /// When set the server will not play any sounds on the notification.
+    SuppressSound(bool),
+
+    /// When set the notification will be placed closest to the x, y coordinates instead of
+    /// being positioned automatically by the server.
+    X(i32),
+
+    /// See `X`.
+    Y(i32),
+
+    /// Whether this notification replaces another one (GNOME only I believe).
+    Transient(bool),
+
+    /// The type of notification this is, see `Category`.
+    Category(String),
+
+    /// The urgency of this notification.
+    Urgency(Urgency),
+
+    /// Custom hints, mostly application specific.
+    Custom(String, String),
+
+    /// Custom hints, with integer value.
+    CustomInt(String, i32),
+
+    /// Catch-all for a hint this crate cannot represent. It has no key, and `hints_to_map` does
+    /// not send it.
+    Invalid(String),
+}
+
+impl Hint {
+    /// The key this hint is sent under in the `hints` map.
+    ///
+    /// `Custom`, `CustomInt` and `Invalid` return an empty string: custom hints are sent under
+    /// their own key, and `Invalid` is not sent at all.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Hint::DesktopEntry(_) => "desktop-entry",
+            Hint::ImageData(_) => "image-data",
+            Hint::Resident(_) => "resident",
+            Hint::SoundFile(_) => "sound-file",
+            Hint::SoundName(_) => "sound-name",
+            Hint::SuppressSound(_) => "suppress-sound",
+            Hint::X(_) => "x",
+            Hint::Y(_) => "y",
+            Hint::Transient(_) => "transient",
+            Hint::Category(_) => "category",
+            Hint::Urgency(_) => "urgency",
+            Hint::Custom(..) | Hint::CustomInt(..) | Hint::Invalid(_) => "",
+        }
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+mod zbus_hints {
+    use super::*;
+    use std::convert::TryFrom;
+    use zvariant::Value;
+
+    impl From<&ImageData> for Value<'static> {
+        fn from(image: &ImageData) -> Value<'static> {
+            let data: Vec<u8> = image.data.clone();
+            Value::from((
+                image.width,
+                image.height,
+                image.rowstride,
+                image.has_alpha,
+                image.bits_per_sample,
+                image.channels,
+                data,
+            ))
+        }
+    }
+
+    impl TryFrom<Value<'_>> for ImageData {
+        type Error = zvariant::Error;
+
+        fn try_from(value: Value<'_>) -> std::result::Result<ImageData, zvariant::Error> {
+            let (width, height, rowstride, has_alpha, bits_per_sample, channels, data) =
+                <(i32, i32, i32, bool, i32, i32, Vec<u8>)>::try_from(value)?;
+            Ok(ImageData {
+                width,
+                height,
+                rowstride,
+                has_alpha,
+                bits_per_sample,
+                channels,
+                data,
+            })
+        }
+    }
+
+    /// Turns a slice of `Hint`s into the `a{sv}` map the `Notify` D-Bus call expects.
+    ///
+    /// Keys borrow from `hints` (either a `&'static str` for well-known hints or the `String`
+    /// backing a `Custom`/`CustomInt` hint), so the returned map's lifetime is tied to `hints`.
+    /// `image-data` is additionally mirrored under `image_data` and `icon_data`, the keys used by
+    /// older servers that predate the current hint name.
+    pub fn hints_to_map(hints: &[Hint]) -> HashMap<&str, Value<'static>> {
+        let mut map = HashMap::new();
+
+        for hint in hints {
+            match hint {
+                Hint::DesktopEntry(value) => {
+                    map.insert(hint.key(), Value::from(value.clone()));
+                }
+                Hint::ImageData(image) => {
+                    let value = Value::from(image);
+                    map.insert("image-data", value.clone());
+                    map.insert("image_data", value.clone());
+                    map.insert("icon_data", value);
+                }
+                Hint::Resident(value) => {
+                    map.insert(hint.key(), Value::from(*value));
+                }
+                Hint::SoundFile(value) => {
+                    map.insert(hint.key(), Value::from(value.clone()));
+                }
+                Hint::SoundName(value) => {
+                    map.insert(hint.key(), Value::from(value.clone()));
+                }
+                Hint::SuppressSound(value) => {
+                    map.insert(hint.key(), Value::from(*value));
+                }
+                Hint::X(value) => {
+                    map.insert(hint.key(), Value::from(*value));
+                }
+                Hint::Y(value) => {
+                    map.insert(hint.key(), Value::from(*value));
+                }
+                Hint::Transient(value) => {
+                    map.insert(hint.key(), Value::from(*value));
+                }
+                Hint::Category(value) => {
+                    map.insert(hint.key(), Value::from(value.clone()));
+                }
+                Hint::Urgency(value) => {
+                    map.insert(hint.key(), Value::from(u8::from(*value)));
+                }
+                Hint::Custom(key, value) => {
+                    map.insert(key.as_str(), Value::from(value.clone()));
+                }
+                Hint::CustomInt(key, value) => {
+                    map.insert(key.as_str(), Value::from(*value));
+                }
+                Hint::Invalid(_) => {}
+            }
+        }
+
+        map
+    }
+
+    /// The inverse of [`hints_to_map`]: turns an incoming `a{sv}` hints map (as received by a
+    /// `NotificationServer`) back into `Hint`s, so server implementations get the same `Hint`
+    /// type the client side builds notifications with.
+    ///
+    /// Keys this crate doesn't recognize become `Custom`/`CustomInt` (or are dropped if they're
+    /// neither a string nor an integer).
+    #[cfg(feature = "server")]
+    pub fn hints_from_map(map: &HashMap<String, Value<'_>>) -> Vec<Hint> {
+        let mut hints = Vec::with_capacity(map.len());
+
+        for (key, value) in map {
+            match key.as_str() {
+                "desktop-entry" => {
+                    if let Ok(value) = String::try_from(value.clone()) {
+                        hints.push(Hint::DesktopEntry(value));
+                    }
+                }
+                "image-data" | "image_data" | "icon_data" => {
+                    if let Ok(image) = ImageData::try_from(value.clone()) {
+                        hints.push(Hint::ImageData(image));
+                    }
+                }
+                "resident" => {
+                    if let Ok(value) = bool::try_from(value.clone()) {
+                        hints.push(Hint::Resident(value));
+                    }
+                }
+                "sound-file" => {
+                    if let Ok(value) = String::try_from(value.clone()) {
+                        hints.push(Hint::SoundFile(value));
+                    }
+                }
+                "sound-name" => {
+                    if let Ok(value) = String::try_from(value.clone()) {
+                        hints.push(Hint::SoundName(value));
+                    }
+                }
+                "suppress-sound" => {
+                    if let Ok(value) = bool::try_from(value.clone()) {
+                        hints.push(Hint::SuppressSound(value));
+                    }
+                }
+                "x" => {
+                    if let Ok(value) = i32::try_from(value.clone()) {
+                        hints.push(Hint::X(value));
+                    }
+                }
+                "y" => {
+                    if let Ok(value) = i32::try_from(value.clone()) {
+                        hints.push(Hint::Y(value));
+                    }
+                }
+                "transient" => {
+                    if let Ok(value) = bool::try_from(value.clone()) {
+                        hints.push(Hint::Transient(value));
+                    }
+                }
+                "category" => {
+                    if let Ok(value) = String::try_from(value.clone()) {
+                        hints.push(Hint::Category(value));
+                    }
+                }
+                "urgency" => {
+                    if let Ok(value) = u8::try_from(value.clone()) {
+                        if let Ok(urgency) = Urgency::try_from(value) {
+                            hints.push(Hint::Urgency(urgency));
+                        }
+                    }
+                }
+                other => {
+                    if let Ok(value) = String::try_from(value.clone()) {
+                        hints.push(Hint::Custom(other.to_owned(), value));
+                    } else if let Ok(value) = i32::try_from(value.clone()) {
+                        hints.push(Hint::CustomInt(other.to_owned(), value));
+                    }
+                }
+            }
+        }
+
+        hints
+    }
+
+    #[cfg(all(test, feature = "server"))]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn round_trips_well_known_hints_through_the_map() {
+            let hints = vec![
+                Hint::Resident(true),
+                Hint::Urgency(Urgency::Critical),
+                Hint::Category("email".into()),
+                Hint::Custom("x-my-app-field".into(), "42".into()),
+            ];
+
+            let owned_map: HashMap<String, Value<'static>> =
+                hints_to_map(&hints).into_iter().map(|(key, value)| (key.to_owned(), value)).collect();
+
+            let mut round_tripped = hints_from_map(&owned_map);
+            round_tripped.sort_by_key(Hint::key);
+            let mut expected = hints;
+            expected.sort_by_key(Hint::key);
+
+            assert_eq!(round_tripped, expected);
+        }
+    }
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+pub use zbus_hints::hints_to_map;
+
+#[cfg(all(feature = "server", unix, not(target_os = "macos")))]
+pub use zbus_hints::hints_from_map;
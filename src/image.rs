@@ -0,0 +1,52 @@
+//! Raw image support for the `image-data` hint.
+//!
+//! The freedesktop notification spec lets clients embed a raw image directly, instead of
+//! pointing the server at a named icon or a file path. This is what `Hint::ImageData` carries.
+
+use crate::error::*;
+
+/// A raw, uncompressed image, matching the `(iiibiiay)` signature of the `image-data` hint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageData {
+    /// Image width in pixels.
+    pub width: i32,
+    /// Image height in pixels.
+    pub height: i32,
+    /// Number of bytes between the start of consecutive rows.
+    pub rowstride: i32,
+    /// Whether each pixel has an alpha channel.
+    pub has_alpha: bool,
+    /// Bits per sample (usually 8).
+    pub bits_per_sample: i32,
+    /// Number of channels per pixel (3 for RGB, 4 for RGBA).
+    pub channels: i32,
+    /// The raw, row-major pixel data.
+    pub data: Vec<u8>,
+}
+
+impl ImageData {
+    /// Builds an `ImageData` from an already-decoded RGBA buffer.
+    pub fn from_rgba(width: i32, height: i32, data: Vec<u8>) -> Result<ImageData> {
+        let channels = 4;
+        let rowstride = width * channels;
+
+        Ok(ImageData {
+            width,
+            height,
+            rowstride,
+            has_alpha: true,
+            bits_per_sample: 8,
+            channels,
+            data,
+        })
+    }
+
+    /// Decodes the image at `path` into an `ImageData` suitable for the `image-data` hint.
+    #[cfg(feature = "images")]
+    pub fn open(path: impl AsRef<std::path::Path>) -> Result<ImageData> {
+        let image = image::open(path.as_ref())?;
+        let rgba = image.to_rgba8();
+        let (width, height) = rgba.dimensions();
+        ImageData::from_rgba(width as i32, height as i32, rgba.into_raw())
+    }
+}
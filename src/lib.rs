@@ -0,0 +1,41 @@
+//! For a quick start check out `Notification`
+//!
+//! ```no_run
+//! # use notify_rust::*;
+//! Notification::new()
+//!     .summary("Firefox News")
+//!     .body("This will almost look like a real firefox notification.")
+//!     .icon("firefox")
+//!     .show()
+//!     .unwrap();
+//! ```
+
+mod error;
+mod hints;
+mod image;
+mod notification;
+mod timeout;
+mod urgency;
+
+#[cfg(all(unix, not(target_os = "macos")))]
+mod xdg;
+
+#[cfg(all(feature = "server", unix, not(target_os = "macos")))]
+mod server;
+
+#[cfg(all(unix, not(target_os = "macos")))]
+mod ratelimit;
+
+pub use crate::{error::*, hints::Hint, image::ImageData, notification::Notification, timeout::Timeout, urgency::Urgency};
+
+#[cfg(all(unix, not(target_os = "macos")))]
+pub use crate::ratelimit::RateLimiter;
+
+#[cfg(all(feature = "server", unix, not(target_os = "macos")))]
+pub use crate::server::{CloseReason, NotificationServer, NotificationServerBuilder};
+
+#[cfg(all(unix, not(target_os = "macos")))]
+pub use crate::xdg::{get_capabilities, get_server_information, handle_action, NotificationHandle, ServerInformation};
+
+#[cfg(all(feature = "async", unix, not(target_os = "macos")))]
+pub use crate::xdg::AsyncNotificationHandle;
@@ -0,0 +1,162 @@
+use crate::{error::*, hints::Hint, image::ImageData, timeout::Timeout, xdg, NotificationHandle};
+
+/// Desktop notification.
+///
+/// # Example
+/// ```no_run
+/// # use notify_rust::Notification;
+/// Notification::new()
+///     .summary("Firefox News")
+///     .body("This will almost look like a real firefox notification.")
+///     .icon("firefox")
+///     .timeout(notify_rust::Timeout::Milliseconds(6000))
+///     .show()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    /// Name of the application sending the notification.
+    pub appname: String,
+
+    /// A short one line summary.
+    pub summary: String,
+
+    /// The longer, optional body text. May contain simple markup on some servers.
+    pub body: String,
+
+    /// The icon, either a name looked up in the icon theme or a path to a file.
+    pub icon: String,
+
+    /// Identifiers paired with human readable labels, describing the actions a user can invoke.
+    pub actions: Vec<String>,
+
+    /// Extra hints that servers can use to render the notification differently.
+    pub hints: Vec<Hint>,
+
+    /// How long the notification should stay on screen for.
+    pub timeout: Timeout,
+
+    /// Id of the notification to replace, if any.
+    pub(crate) id: Option<u32>,
+}
+
+impl Default for Notification {
+    fn default() -> Notification {
+        Notification {
+            appname: exe_name(),
+            summary: String::new(),
+            body: String::new(),
+            icon: String::new(),
+            actions: Vec::new(),
+            hints: Vec::new(),
+            timeout: Timeout::Default,
+            id: None,
+        }
+    }
+}
+
+impl Notification {
+    /// Constructs a new `Notification`, ready to be filled in with builder methods.
+    pub fn new() -> Notification {
+        Notification::default()
+    }
+
+    /// Overwrites the application name used to send the notification.
+    pub fn appname(&mut self, appname: &str) -> &mut Notification {
+        self.appname = appname.to_owned();
+        self
+    }
+
+    /// Set the `summary`.
+    pub fn summary(&mut self, summary: &str) -> &mut Notification {
+        self.summary = summary.to_owned();
+        self
+    }
+
+    /// Set the `body`.
+    pub fn body(&mut self, body: &str) -> &mut Notification {
+        self.body = body.to_owned();
+        self
+    }
+
+    /// Set the `icon` by name or path, as understood by the running notification server.
+    pub fn icon(&mut self, icon: &str) -> &mut Notification {
+        self.icon = icon.to_owned();
+        self
+    }
+
+    /// Embed a raw, already-decoded image via the `image-data` hint, instead of referencing an
+    /// icon by name or path.
+    pub fn image_data(&mut self, image: ImageData) -> &mut Notification {
+        self.hint(Hint::ImageData(image))
+    }
+
+    /// Load the image at `path` and embed it via the `image-data` hint.
+    #[cfg(feature = "images")]
+    pub fn image_path(&mut self, path: impl AsRef<std::path::Path>) -> Result<&mut Notification> {
+        let image = ImageData::open(path)?;
+        Ok(self.image_data(image))
+    }
+
+    /// Add a hint. Hints are not deduplicated here; when the notification is sent, a later hint
+    /// with the same key replaces an earlier one.
+    pub fn hint(&mut self, hint: Hint) -> &mut Notification {
+        self.hints.push(hint);
+        self
+    }
+
+    /// Set the `timeout`.
+    pub fn timeout<T: Into<Timeout>>(&mut self, timeout: T) -> &mut Notification {
+        self.timeout = timeout.into();
+        self
+    }
+
+    /// Finalizes the notification, turning the builder into a plain, owned value.
+    pub fn finalize(&self) -> Notification {
+        self.clone()
+    }
+
+    /// Sends the notification to the notification server.
+    #[cfg(all(unix, not(target_os = "macos")))]
+    pub fn show(&self) -> Result<NotificationHandle> {
+        xdg::show_notification(self)
+    }
+
+    /// Like [`Notification::show`], but passed through a [`RateLimiter`](crate::RateLimiter). See
+    /// [`RateLimiter`](crate::RateLimiter)'s docs for what this buys you over calling `show()`
+    /// directly.
+    ///
+    /// Returns the id of the notification left on screen, or `None` if this call was dropped.
+    /// Unlike `show()`, this does not return a [`NotificationHandle`] — the limiter has to keep
+    /// ownership of the handle internally to coalesce future notifications into it via
+    /// `update()`. If you need `wait_for_action`/`close`/etc. on a specific notification, call
+    /// `show()` directly instead.
+    #[cfg(all(unix, not(target_os = "macos")))]
+    pub fn show_throttled(&self, limiter: &crate::RateLimiter) -> Result<Option<u32>> {
+        limiter.show(self)
+    }
+
+    /// Async mirror of [`Notification::show`], for callers that don't want to block the calling
+    /// thread. Only the zbus backend has async support.
+    #[cfg(all(feature = "async", unix, not(target_os = "macos")))]
+    pub async fn show_async(&self) -> Result<xdg::AsyncNotificationHandle> {
+        xdg::show_notification_async(self).await
+    }
+
+    /// Shows the notification and blocks until the user acts on it or dismisses it.
+    pub fn show_and_wait_for_action<F>(&self, invocation_closure: F)
+    where
+        F: FnOnce(&str),
+    {
+        if let Ok(handle) = self.show() {
+            handle.wait_for_action(invocation_closure);
+        }
+    }
+}
+
+fn exe_name() -> String {
+    std::env::current_exe()
+        .ok()
+        .and_then(|path| path.file_stem().map(|name| name.to_string_lossy().into_owned()))
+        .unwrap_or_default()
+}
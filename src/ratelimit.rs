@@ -0,0 +1,147 @@
+//! Token-bucket rate limiting for bursty callers (mail sync, chat, ...) that would otherwise
+//! flood the notification daemon.
+
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::{error::*, Notification, NotificationHandle};
+
+struct State {
+    tokens: f64,
+    capacity: f64,
+    refill_per_sec: f64,
+    min_interval: Duration,
+    last_sent: Option<Instant>,
+    last_refill: Instant,
+    coalesced: Option<NotificationHandle>,
+}
+
+impl State {
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.last_refill = now;
+    }
+
+    /// Refills the bucket, then tries to take a token. Returns `true` if a token was taken (i.e.
+    /// the caller should show a new notification), `false` if the call should coalesce or drop
+    /// instead.
+    fn should_send(&mut self) -> bool {
+        self.refill();
+
+        let too_soon = self.last_sent.map(|last| last.elapsed() < self.min_interval).unwrap_or(false);
+
+        if !too_soon && self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            self.last_sent = Some(Instant::now());
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// A token bucket that caps how often [`Notification::show_throttled`] actually shows a new
+/// notification.
+///
+/// Notifications sent while the bucket is empty, or sooner than `min_interval` after the last
+/// one, are coalesced into the previously shown notification via
+/// [`NotificationHandle::update`](crate::NotificationHandle::update) instead of stacking up. If
+/// there is nothing to coalesce into yet, they're dropped.
+pub struct RateLimiter {
+    state: Mutex<State>,
+}
+
+impl RateLimiter {
+    /// Allows a burst of up to `max_per_window` notifications, refilled at `max_per_window` per
+    /// `window`, with at least `min_interval` between any two notifications actually shown.
+    pub fn new(max_per_window: u32, window: Duration, min_interval: Duration) -> RateLimiter {
+        let capacity = max_per_window as f64;
+
+        RateLimiter {
+            state: Mutex::new(State {
+                tokens: capacity,
+                capacity,
+                refill_per_sec: capacity / window.as_secs_f64(),
+                min_interval,
+                last_sent: None,
+                last_refill: Instant::now(),
+                coalesced: None,
+            }),
+        }
+    }
+
+    /// Returns the id of the notification left on screen, or `None` if this call was dropped
+    /// outright (bucket empty and nothing to coalesce into yet).
+    ///
+    /// This can't hand back a [`NotificationHandle`] the way [`Notification::show`] does: the
+    /// limiter has to keep owning the handle internally so it can coalesce *future* notifications
+    /// into it via `update()`. Callers that need `wait_for_action`/`close`/etc. on a specific
+    /// notification should use `show()` directly and manage their own rate limiting around it.
+    pub(crate) fn show(&self, notification: &Notification) -> Result<Option<u32>> {
+        let mut state = self.state.lock().unwrap();
+
+        if state.should_send() {
+            let handle = notification.show()?;
+            let id = handle.id();
+            state.coalesced = Some(handle);
+            return Ok(Some(id));
+        }
+
+        if let Some(handle) = state.coalesced.as_mut() {
+            **handle = notification.finalize();
+            handle.update();
+            return Ok(Some(handle.id()));
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(max_per_window: u32, window: Duration, min_interval: Duration) -> State {
+        let capacity = max_per_window as f64;
+        State {
+            tokens: capacity,
+            capacity,
+            refill_per_sec: capacity / window.as_secs_f64(),
+            min_interval,
+            last_sent: None,
+            last_refill: Instant::now(),
+            coalesced: None,
+        }
+    }
+
+    #[test]
+    fn allows_a_burst_up_to_capacity() {
+        let mut state = state(3, Duration::from_secs(60), Duration::from_millis(0));
+        assert!(state.should_send());
+        assert!(state.should_send());
+        assert!(state.should_send());
+        assert!(!state.should_send());
+    }
+
+    #[test]
+    fn refills_tokens_over_time() {
+        let mut state = state(1, Duration::from_millis(20), Duration::from_millis(0));
+        assert!(state.should_send());
+        assert!(!state.should_send());
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(state.should_send());
+    }
+
+    #[test]
+    fn enforces_min_interval_even_with_tokens_left() {
+        let mut state = state(5, Duration::from_secs(60), Duration::from_millis(30));
+        assert!(state.should_send());
+        assert!(!state.should_send());
+        std::thread::sleep(Duration::from_millis(40));
+        assert!(state.should_send());
+    }
+}
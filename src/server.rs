@@ -0,0 +1,215 @@
+//! A minimal `org.freedesktop.Notifications` server.
+//!
+//! This lets downstream projects build a custom notification daemon on top of the same
+//! [`Notification`]/[`Hint`](crate::Hint) types the client side already knows how to parse,
+//! instead of hand-rolling the D-Bus interface again.
+
+use std::{convert::TryFrom, marker::PhantomData};
+
+use zbus::{dbus_interface, fdo, Connection, ObjectServer};
+use zvariant::Value;
+
+use crate::{
+    error::*,
+    hints::hints_from_map,
+    timeout::Timeout,
+    xdg::{NOTIFICATION_NAMESPACE, NOTIFICATION_OBJECTPATH},
+    Notification, ServerInformation,
+};
+
+/// Why a notification was closed, passed along with the `NotificationClosed` signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloseReason {
+    /// The notification expired.
+    Expired = 1,
+    /// The user dismissed the notification.
+    Dismissed = 2,
+    /// A client asked for the notification to be closed via `CloseNotification`.
+    CloseNotification = 3,
+    /// Undefined/reserved reason.
+    Undefined = 4,
+}
+
+/// Emits `NotificationClosed(id, reason)` directly over `connection`.
+///
+/// `#[dbus_interface(signal)]` stubs only work from within message dispatch (they rely on a
+/// thread-local node set up by `ObjectServer::dispatch_message`), so emitting this signal both
+/// from inside `Handler::close_notification` and from the outside (via
+/// [`NotificationServer::emit_notification_closed`]) goes through `Connection::emit_signal`
+/// instead.
+fn emit_notification_closed(connection: &Connection, id: u32, reason: CloseReason) -> Result<()> {
+    connection.emit_signal(None, NOTIFICATION_OBJECTPATH, NOTIFICATION_NAMESPACE, "NotificationClosed", &(id, reason as u32))?;
+    Ok(())
+}
+
+/// Emits `ActionInvoked(id, action_key)` directly over `connection`. See
+/// [`emit_notification_closed`] for why this doesn't go through a `#[dbus_interface(signal)]`
+/// stub.
+fn emit_action_invoked(connection: &Connection, id: u32, action_key: &str) -> Result<()> {
+    connection.emit_signal(None, NOTIFICATION_OBJECTPATH, NOTIFICATION_NAMESPACE, "ActionInvoked", &(id, action_key))?;
+    Ok(())
+}
+
+struct Handler<F> {
+    connection: Connection,
+    capabilities: Vec<String>,
+    server_information: ServerInformation,
+    on_notify: F,
+}
+
+#[dbus_interface(name = "org.freedesktop.Notifications")]
+impl<F> Handler<F>
+where
+    F: FnMut(Notification) -> u32 + Send + 'static,
+{
+    #[allow(clippy::too_many_arguments)]
+    fn notify(
+        &mut self,
+        app_name: String,
+        replaces_id: u32,
+        app_icon: String,
+        summary: String,
+        body: String,
+        actions: Vec<String>,
+        hints: std::collections::HashMap<String, Value>,
+        expire_timeout: i32,
+    ) -> u32 {
+        let mut notification = Notification::new();
+        notification
+            .appname(&app_name)
+            .icon(&app_icon)
+            .summary(&summary)
+            .body(&body)
+            .timeout(Timeout::from(expire_timeout));
+        notification.actions = actions;
+        notification.hints = hints_from_map(&hints);
+        if replaces_id != 0 {
+            notification.id = Some(replaces_id);
+        }
+
+        (self.on_notify)(notification.finalize())
+    }
+
+    fn close_notification(&mut self, id: u32) {
+        // The signal is best-effort; a client that already went away shouldn't bring the server
+        // down.
+        let _ = emit_notification_closed(&self.connection, id, CloseReason::CloseNotification);
+    }
+
+    fn get_capabilities(&self) -> Vec<String> {
+        self.capabilities.clone()
+    }
+
+    fn get_server_information(&self) -> (String, String, String, String) {
+        let info = &self.server_information;
+        (info.name.clone(), info.vendor.clone(), info.version.clone(), info.spec_version.clone())
+    }
+}
+
+/// Builds a [`NotificationServer`].
+pub struct NotificationServerBuilder {
+    capabilities: Vec<String>,
+    server_information: ServerInformation,
+}
+
+impl Default for NotificationServerBuilder {
+    fn default() -> NotificationServerBuilder {
+        NotificationServerBuilder {
+            capabilities: vec!["body".to_owned(), "actions".to_owned()],
+            server_information: ServerInformation {
+                name: "notify-rust".to_owned(),
+                vendor: "notify-rust".to_owned(),
+                version: env!("CARGO_PKG_VERSION").to_owned(),
+                spec_version: "1.2".to_owned(),
+            },
+        }
+    }
+}
+
+impl NotificationServerBuilder {
+    /// Starts a new builder with the default capabilities (`body`, `actions`).
+    pub fn new() -> NotificationServerBuilder {
+        NotificationServerBuilder::default()
+    }
+
+    /// Overwrites the capabilities advertised via `GetCapabilities`, e.g. `"body-markup"`,
+    /// `"icon-static"`.
+    pub fn capabilities(mut self, capabilities: Vec<String>) -> NotificationServerBuilder {
+        self.capabilities = capabilities;
+        self
+    }
+
+    /// Overwrites the information returned by `GetServerInformation`.
+    pub fn server_information(mut self, server_information: ServerInformation) -> NotificationServerBuilder {
+        self.server_information = server_information;
+        self
+    }
+
+    /// Connects to the session bus, claims `org.freedesktop.Notifications` and returns a server
+    /// ready to `run()`. `on_notify` is invoked for every incoming `Notify` call and must return
+    /// the id assigned to that notification.
+    pub fn build<F>(self, on_notify: F) -> Result<NotificationServer<F>>
+    where
+        F: FnMut(Notification) -> u32 + Send + 'static,
+    {
+        let connection = Connection::new_session()?;
+
+        fdo::DBusProxy::new(&connection)?
+            .request_name(NOTIFICATION_NAMESPACE, fdo::RequestNameFlags::ReplaceExisting.into())
+            .map_err(zbus::Error::from)?;
+
+        let handler = Handler {
+            connection: connection.clone(),
+            capabilities: self.capabilities,
+            server_information: self.server_information,
+            on_notify,
+        };
+
+        let object_path = zvariant::ObjectPath::try_from(NOTIFICATION_OBJECTPATH).map_err(zbus::Error::from)?;
+        let mut object_server = ObjectServer::new(&connection);
+        object_server.at(&object_path, handler)?;
+
+        Ok(NotificationServer {
+            connection,
+            object_server,
+            _handler: PhantomData,
+        })
+    }
+}
+
+/// A running (or ready-to-run) `org.freedesktop.Notifications` server.
+///
+/// Build one with [`NotificationServerBuilder`].
+pub struct NotificationServer<F> {
+    connection: Connection,
+    object_server: ObjectServer<'static>,
+    _handler: PhantomData<F>,
+}
+
+impl<F> NotificationServer<F>
+where
+    F: FnMut(Notification) -> u32 + Send + 'static,
+{
+    /// Dispatches incoming D-Bus messages forever, handing each `Notify` call to the callback
+    /// passed to [`NotificationServerBuilder::build`].
+    pub fn run(&mut self) -> Result<()> {
+        loop {
+            let message = self.connection.receive_message()?;
+            if let Err(err) = self.object_server.dispatch_message(&message) {
+                eprintln!("notify-rust: error dispatching message: {}", err);
+            }
+        }
+    }
+
+    /// Emits `ActionInvoked(id, action_key)`, e.g. once a downstream UI records that the user
+    /// invoked an action on notification `id`.
+    pub fn emit_action_invoked(&self, id: u32, action_key: &str) -> Result<()> {
+        emit_action_invoked(&self.connection, id, action_key)
+    }
+
+    /// Emits `NotificationClosed(id, reason)`, e.g. once a downstream UI dismisses or expires
+    /// notification `id` itself, outside of a `CloseNotification` call.
+    pub fn emit_notification_closed(&self, id: u32, reason: CloseReason) -> Result<()> {
+        emit_notification_closed(&self.connection, id, reason)
+    }
+}
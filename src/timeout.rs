@@ -0,0 +1,59 @@
+//! Helper for the `expire_timeout` argument of `Notify`.
+
+/// Sort of an Enum to specify the notification's expiration timeout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Timeout {
+    /// Expires after n milliseconds.
+    Milliseconds(u32),
+    /// Never expires, has to be closed by the user.
+    Never,
+    /// Let the server decide the timeout.
+    #[default]
+    Default,
+}
+
+impl Timeout {
+    /// Converts the `Timeout` to the `i32` expected by the `Notify` D-Bus call.
+    pub fn into_i32(self) -> i32 {
+        match self {
+            Timeout::Default => -1,
+            Timeout::Never => 0,
+            Timeout::Milliseconds(ms) => ms as i32,
+        }
+    }
+}
+
+impl From<i32> for Timeout {
+    fn from(i: i32) -> Timeout {
+        match i {
+            -1 => Timeout::Default,
+            0 => Timeout::Never,
+            ms => Timeout::Milliseconds(ms as u32),
+        }
+    }
+}
+
+impl From<Timeout> for i32 {
+    fn from(timeout: Timeout) -> i32 {
+        timeout.into_i32()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn into_i32_matches_the_notify_spec() {
+        assert_eq!(Timeout::Default.into_i32(), -1);
+        assert_eq!(Timeout::Never.into_i32(), 0);
+        assert_eq!(Timeout::Milliseconds(2000).into_i32(), 2000);
+    }
+
+    #[test]
+    fn from_i32_is_the_inverse_of_into_i32() {
+        assert_eq!(Timeout::from(-1), Timeout::Default);
+        assert_eq!(Timeout::from(0), Timeout::Never);
+        assert_eq!(Timeout::from(2000), Timeout::Milliseconds(2000));
+    }
+}
@@ -0,0 +1,53 @@
+//! The urgency hint, as defined by the notification spec.
+
+/// Level of urgency of a notification, see `Hint::Urgency`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Urgency {
+    /// Low urgency, usually no sound and a short timeout.
+    Low,
+    /// Normal urgency.
+    Normal,
+    /// Critical urgency, usually ignores the timeout until dismissed by the user.
+    Critical,
+}
+
+impl From<Urgency> for u8 {
+    fn from(urgency: Urgency) -> u8 {
+        match urgency {
+            Urgency::Low => 0,
+            Urgency::Normal => 1,
+            Urgency::Critical => 2,
+        }
+    }
+}
+
+impl std::convert::TryFrom<u8> for Urgency {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Urgency, ()> {
+        match value {
+            0 => Ok(Urgency::Low),
+            1 => Ok(Urgency::Normal),
+            2 => Ok(Urgency::Critical),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn round_trips_through_u8() {
+        for urgency in [Urgency::Low, Urgency::Normal, Urgency::Critical] {
+            assert_eq!(Urgency::try_from(u8::from(urgency)), Ok(urgency));
+        }
+    }
+
+    #[test]
+    fn rejects_out_of_range_values() {
+        assert_eq!(Urgency::try_from(3), Err(()));
+    }
+}
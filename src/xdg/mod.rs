@@ -2,62 +2,45 @@
 //!
 //! it should not be available under any platform other than `(unix, not(target_os = "macos"))`
 
-use dbus::ffidisp::Connection as DbusConnection;
-
 use crate::{error::*, notification::Notification};
 
 use std::ops::{Deref, DerefMut};
 
-mod dbus_rs;
+mod proxy;
 mod zbus_rs;
 
 #[cfg(not(feature = "debug_namespace"))]
 pub static NOTIFICATION_NAMESPACE: &str = "org.freedesktop.Notifications";
 #[cfg(not(feature = "debug_namespace"))]
+#[cfg_attr(not(any(feature = "async", feature = "server")), allow(dead_code))]
 pub static NOTIFICATION_OBJECTPATH: &str = "/org/freedesktop/Notifications";
 
 #[cfg(feature = "debug_namespace")]
 pub static NOTIFICATION_NAMESPACE: &str = "de.hoodie.Notifications";
 #[cfg(feature = "debug_namespace")]
+#[cfg_attr(not(any(feature = "async", feature = "server")), allow(dead_code))]
 pub static NOTIFICATION_OBJECTPATH: &str = "/de/hoodie/Notifications";
 
-#[derive(Debug)]
-enum NotificationHandleInner {
-    Dbus(dbus_rs::DbusNotificationHandle),
-    Zbus(zbus_rs::ZbusNotificationHandle),
-}
-
 /// A handle to a shown notification.
 ///
 /// This keeps a connection alive to ensure actions work on certain desktops.
+///
+/// zbus is this crate's only D-Bus backend; there used to be a `dbus-rs`-based one too, but it
+/// was never actually ported into this tree, so this is a thin wrapper around
+/// [`zbus_rs::ZbusNotificationHandle`] rather than an enum over two backends.
 #[derive(Debug)]
 pub struct NotificationHandle {
-    inner: NotificationHandleInner,
+    inner: zbus_rs::ZbusNotificationHandle,
 }
 
 impl NotificationHandle {
-    pub(crate) fn for_dbus(id: u32, connection: DbusConnection, notification: Notification) -> NotificationHandle {
-        NotificationHandle {
-            inner: dbus_rs::DbusNotificationHandle::new(id, connection, notification).into(),
-        }
-    }
-
-    pub(crate) fn for_zbus(id: u32, connection: zbus::Connection, notification: Notification) -> NotificationHandle {
-        NotificationHandle {
-            inner: zbus_rs::ZbusNotificationHandle::new(id, connection, notification).into(),
-        }
-    }
-
     /// Waits for the user to act on a notification and then calls
     /// `invocation_closure` with the name of the corresponding action.
     pub fn wait_for_action<F>(self, invocation_closure: F)
     where
         F: FnOnce(&str),
     {
-        match self.inner {
-            NotificationHandleInner::Dbus(inner) => inner.wait_for_action(invocation_closure),
-            NotificationHandleInner::Zbus(inner) => inner.wait_for_action(invocation_closure),
-        }
+        self.inner.wait_for_action(invocation_closure);
     }
 
     /// Manually close the notification
@@ -65,9 +48,10 @@ impl NotificationHandle {
     /// # Example
     /// see
     /// ```no_run
+    /// # use notify_rust::{Notification, Hint, Timeout};
     /// let handle: notify_rust::NotificationHandle = Notification::new()
     ///     .summary("oh no")
-    ///     .hint(notify_rust::Hint::Transient(true))
+    ///     .hint(Hint::Transient(true))
     ///     .body("I'll be here till you close me!")
     ///     .hint(Hint::Resident(true)) // does not work on kde
     ///     .timeout(Timeout::Never) // works on kde and gnome
@@ -77,10 +61,7 @@ impl NotificationHandle {
     /// handle.close();
     /// ```
     pub fn close(self) {
-        match self.inner {
-            NotificationHandleInner::Dbus(inner) => inner.close(),
-            NotificationHandleInner::Zbus(inner) => inner.close(),
-        }
+        self.inner.close();
     }
 
     /// Executes a closure after the notification has closed.
@@ -98,18 +79,11 @@ impl NotificationHandle {
     where
         F: FnOnce(),
     {
-        match self.inner {
-            NotificationHandleInner::Dbus(inner) => inner.wait_for_action(|action| {
-                if action == "__closed" {
-                    closure();
-                }
-            }),
-            NotificationHandleInner::Zbus(inner) => inner.wait_for_action(|action| {
-                if action == "__closed" {
-                    closure();
-                }
-            }),
-        }
+        self.inner.wait_for_action(|action| {
+            if action == "__closed" {
+                closure();
+            }
+        });
     }
 
     /// Replace the original notification with an updated version
@@ -133,18 +107,12 @@ impl NotificationHandle {
     /// message is really replaced and not just amended. Xfce behaves well, all others have not
     /// been tested by the developer.
     pub fn update(&mut self) {
-        match self.inner {
-            NotificationHandleInner::Dbus(ref mut inner) => inner.update(),
-            NotificationHandleInner::Zbus(ref mut inner) => inner.update(),
-        }
+        self.inner.update();
     }
 
     /// Returns the Handle's id.
     pub fn id(&self) -> u32 {
-        match self.inner {
-            NotificationHandleInner::Dbus(ref inner) => inner.id,
-            NotificationHandleInner::Zbus(ref inner) => inner.id,
-        }
+        self.inner.id
     }
 }
 
@@ -153,74 +121,52 @@ impl Deref for NotificationHandle {
     type Target = Notification;
 
     fn deref(&self) -> &Notification {
-        match self.inner {
-            NotificationHandleInner::Dbus(ref inner) => &inner.notification,
-            NotificationHandleInner::Zbus(ref inner) => &inner.notification,
-        }
+        &self.inner.notification
     }
 }
 
 /// Allow you to easily modify notification properties
 impl DerefMut for NotificationHandle {
     fn deref_mut(&mut self) -> &mut Notification {
-        match self.inner {
-            NotificationHandleInner::Dbus(ref mut inner) => &mut inner.notification,
-            NotificationHandleInner::Zbus(ref mut inner) => &mut inner.notification,
-        }
+        &mut self.inner.notification
     }
 }
 
-impl From<dbus_rs::DbusNotificationHandle> for NotificationHandleInner {
-    fn from(handle: dbus_rs::DbusNotificationHandle) -> NotificationHandleInner {
-        NotificationHandleInner::Dbus(handle)
+impl From<zbus_rs::ZbusNotificationHandle> for NotificationHandle {
+    fn from(handle: zbus_rs::ZbusNotificationHandle) -> NotificationHandle {
+        NotificationHandle { inner: handle }
     }
 }
 
-impl From<zbus_rs::ZbusNotificationHandle> for NotificationHandleInner {
-    fn from(handle: zbus_rs::ZbusNotificationHandle) -> NotificationHandleInner {
-        NotificationHandleInner::Zbus(handle)
-    }
-}
+/// Handle returned by [`show_notification_async`]. Unlike [`NotificationHandle`] it holds an
+/// async zbus connection, not a [`zbus_rs::ZbusNotificationHandle`].
+#[cfg(feature = "async")]
+pub use zbus_rs::AsyncNotificationHandle;
 
-impl From<dbus_rs::DbusNotificationHandle> for NotificationHandle {
-    fn from(handle: dbus_rs::DbusNotificationHandle) -> NotificationHandle {
-        NotificationHandle { inner: handle.into() }
-    }
+/// Async mirror of `show_notification`, routed through the zbus backend since that's the only
+/// one with full async support.
+#[cfg(feature = "async")]
+pub(crate) async fn show_notification_async(notification: &Notification) -> Result<AsyncNotificationHandle> {
+    zbus_rs::connect_and_send_notification_async(notification).await
 }
 
-impl From<zbus_rs::ZbusNotificationHandle> for NotificationHandle {
-    fn from(handle: zbus_rs::ZbusNotificationHandle) -> NotificationHandle {
-        NotificationHandle { inner: handle.into() }
-    }
-}
 pub(crate) fn show_notification(notification: &Notification) -> Result<NotificationHandle> {
-    if std::env::var("ZBUS").is_ok() {
-        eprintln!("using zbus");
-        zbus_rs::connect_and_send_notification(notification).map(Into::into)
-    } else {
-        dbus_rs::connect_and_send_notification(notification).map(Into::into)
-    }
+    zbus_rs::connect_and_send_notification(notification).map(Into::into)
 }
 
 // here be public functions
 
 /// Get list of all capabilities of the running notification server.
 pub fn get_capabilities() -> Result<Vec<String>> {
-    dbus_rs::get_capabilities()
+    zbus_rs::get_capabilities()
 }
 
 /// Returns a struct containing `ServerInformation`.
 ///
 /// This struct contains `name`, `vendor`, `version` and `spec_version` of the notification server
 /// running.
-/// TODO dbus stuff module!!!
 pub fn get_server_information() -> Result<ServerInformation> {
-    if std::env::var("ZBUS").is_ok() {
-        eprintln!("using zbus");
-        zbus_rs::get_server_information()
-    } else {
-        dbus_rs::get_server_information()
-    }
+    zbus_rs::get_server_information()
 }
 /// Return value of `get_server_information()`.
 #[derive(Debug, serde::Deserialize, zvariant_derive::Type)]
@@ -235,15 +181,6 @@ pub struct ServerInformation {
     pub spec_version: String,
 }
 
-/// Strictly internal.
-/// The NotificationServer implemented here exposes a "Stop" function.
-/// stops the notification server
-#[cfg(all(feature = "server", unix, not(target_os = "macos")))]
-#[doc(hidden)]
-pub fn stop_server() {
-    dbus_rs::stop_server()
-}
-
 /// Listens for the `ActionInvoked(UInt32, String)` Signal.
 ///
 /// No need to use this, check out `Notification::show_and_wait_for_action(FnOnce(action:&str))`
@@ -251,5 +188,7 @@ pub fn handle_action<F>(id: u32, func: F)
 where
     F: FnOnce(&str),
 {
-    dbus_rs::handle_action(id, func)
+    if let Ok(connection) = zbus::Connection::new_session() {
+        zbus_rs::wait_for_action_on_id(&connection, id, func);
+    }
 }
@@ -0,0 +1,76 @@
+//! Generated D-Bus proxy for `org.freedesktop.Notifications`.
+//!
+//! Kept separate from `zbus_rs.rs` so the generated code isn't mixed in with the code that
+//! drives it. `#[dbus_proxy]` in this zbus version only generates a blocking proxy wrapping
+//! `zbus::Proxy`; the async path in `zbus_rs.rs` talks to the bus directly instead.
+//!
+//! `#[allow(clippy::too_many_arguments)]` on `notify` below isn't carried over to the code
+//! `#[dbus_proxy]` generates, so it's allowed for the whole module instead.
+#![allow(clippy::too_many_arguments)]
+
+use std::collections::HashMap;
+
+use zbus::dbus_proxy;
+use zvariant::Value;
+
+#[cfg(not(feature = "debug_namespace"))]
+#[dbus_proxy(
+    interface = "org.freedesktop.Notifications",
+    default_service = "org.freedesktop.Notifications",
+    default_path = "/org/freedesktop/Notifications"
+)]
+trait Notifications {
+    /// `Notify` method.
+    #[allow(clippy::too_many_arguments)]
+    fn notify(
+        &self,
+        app_name: &str,
+        replaces_id: u32,
+        app_icon: &str,
+        summary: &str,
+        body: &str,
+        actions: &[&str],
+        hints: HashMap<&str, Value<'_>>,
+        expire_timeout: i32,
+    ) -> zbus::Result<u32>;
+
+    /// `CloseNotification` method.
+    fn close_notification(&self, id: u32) -> zbus::Result<()>;
+
+    /// `GetCapabilities` method.
+    fn get_capabilities(&self) -> zbus::Result<Vec<String>>;
+
+    /// `GetServerInformation` method.
+    fn get_server_information(&self) -> zbus::Result<(String, String, String, String)>;
+}
+
+#[cfg(feature = "debug_namespace")]
+#[dbus_proxy(
+    interface = "de.hoodie.Notifications",
+    default_service = "de.hoodie.Notifications",
+    default_path = "/de/hoodie/Notifications"
+)]
+trait Notifications {
+    /// `Notify` method.
+    #[allow(clippy::too_many_arguments)]
+    fn notify(
+        &self,
+        app_name: &str,
+        replaces_id: u32,
+        app_icon: &str,
+        summary: &str,
+        body: &str,
+        actions: &[&str],
+        hints: HashMap<&str, Value<'_>>,
+        expire_timeout: i32,
+    ) -> zbus::Result<u32>;
+
+    /// `CloseNotification` method.
+    fn close_notification(&self, id: u32) -> zbus::Result<()>;
+
+    /// `GetCapabilities` method.
+    fn get_capabilities(&self) -> zbus::Result<Vec<String>>;
+
+    /// `GetServerInformation` method.
+    fn get_server_information(&self) -> zbus::Result<(String, String, String, String)>;
+}
@@ -1,6 +1,11 @@
 use crate::{error::*, notification::Notification, xdg};
 use zbus::Connection;
 
+#[cfg(feature = "async")]
+use std::os::unix::net::UnixStream;
+
+use super::proxy::NotificationsProxy;
+
 /// A handle to a shown notification.
 ///
 /// This keeps a connection alive to ensure actions work on certain desktops.
@@ -24,12 +29,11 @@ impl ZbusNotificationHandle {
     where
         F: FnOnce(&str),
     {
-        todo!("no action handling yet")
-        //wait_for_action_signal(&self.connection, self.id, invocation_closure);
+        wait_for_action_signal(&self.connection, self.id, invocation_closure);
     }
 
     pub fn close(self) {
-        todo!("can't close notification yet")
+        let _ = close_notification(&self.connection, self.id);
     }
 
     pub fn on_close<F>(self, closure: F)
@@ -48,46 +52,295 @@ impl ZbusNotificationHandle {
     }
 }
 
+/// Async mirror of [`ZbusNotificationHandle`].
+///
+/// `#[dbus_proxy]` only generates a blocking proxy in this zbus version, so the async path can't
+/// share [`NotificationsProxy`] — it drives its own [`zbus::azync::Connection`] directly instead.
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub struct AsyncNotificationHandle {
+    pub(crate) id: u32,
+    pub(crate) connection: zbus::azync::Connection<UnixStream>,
+}
+
+#[cfg(feature = "async")]
+impl AsyncNotificationHandle {
+    pub(crate) fn new(id: u32, connection: zbus::azync::Connection<UnixStream>) -> AsyncNotificationHandle {
+        AsyncNotificationHandle { id, connection }
+    }
+
+    /// Returns the Handle's id.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub async fn wait_for_action<F>(mut self, invocation_closure: F)
+    where
+        F: FnOnce(&str),
+    {
+        wait_for_action_signal_async(&mut self.connection, self.id, invocation_closure).await;
+    }
+
+    pub async fn close(mut self) {
+        let _ = close_notification_async(&mut self.connection, self.id).await;
+    }
+
+    pub async fn on_close<F>(self, closure: F)
+    where
+        F: FnOnce(),
+    {
+        self.wait_for_action(|action| {
+            if action == "__closed" {
+                closure();
+            }
+        })
+        .await;
+    }
+}
+
+fn notify_args(notification: &Notification, id: u32) -> (String, u32, String, String, String, Vec<String>, i32) {
+    (
+        notification.appname.clone(),
+        id,
+        notification.icon.clone(),
+        notification.summary.clone(),
+        notification.body.clone(),
+        notification.actions.clone(),
+        notification.timeout.into_i32(),
+    )
+}
+
 pub fn send_notificaion_via_connection(notification: &Notification, id: u32, connection: &Connection) -> Result<u32> {
-    let reply: u32 = connection
+    let proxy = NotificationsProxy::new(connection)?;
+    let (app_name, id, app_icon, summary, body, actions, expire_timeout) = notify_args(notification, id);
+    let actions: Vec<&str> = actions.iter().map(String::as_str).collect();
+
+    let reply = proxy.notify(
+        &app_name,
+        id,
+        &app_icon,
+        &summary,
+        &body,
+        &actions,
+        crate::hints::hints_to_map(&notification.hints),
+        expire_timeout,
+    )?;
+    Ok(reply)
+}
+
+pub fn connect_and_send_notification(notification: &Notification) -> Result<ZbusNotificationHandle> {
+    let connection = zbus::Connection::new_session()?;
+    let inner_id = notification.id.unwrap_or(0);
+    let id = send_notificaion_via_connection(notification, inner_id, &connection)?;
+    Ok(ZbusNotificationHandle::new(id, connection, notification.clone()))
+}
+
+pub fn close_notification(connection: &Connection, id: u32) -> Result<()> {
+    let proxy = NotificationsProxy::new(connection)?;
+    proxy.close_notification(id)?;
+    Ok(())
+}
+
+/// Blocks until either an `ActionInvoked` or a `NotificationClosed` signal concerning `id` is
+/// received, then hands the corresponding action key to `invocation_closure`.
+///
+/// A `NotificationClosed` signal is translated to the synthetic `"__closed"` action key, matching
+/// what `on_close()` in `xdg/mod.rs` expects.
+///
+/// [`NotificationsProxy`] declares no signals, so this does not go through the proxy: it adds a
+/// match rule for the interface, reads the raw message stream off the connection and dispatches
+/// on whichever of the two signals arrives.
+fn wait_for_action_signal<F>(connection: &Connection, id: u32, invocation_closure: F)
+where
+    F: FnOnce(&str),
+{
+    let rule = format!("interface='{}'", crate::xdg::NOTIFICATION_NAMESPACE);
+    if connection
+        .call_method(
+            Some("org.freedesktop.DBus"),
+            "/org/freedesktop/DBus",
+            Some("org.freedesktop.DBus"),
+            "AddMatch",
+            &(rule,),
+        )
+        .is_err()
+    {
+        return;
+    }
+
+    loop {
+        let message = match connection.receive_message() {
+            Ok(message) => message,
+            Err(_) => return,
+        };
+
+        let member = match message.header().and_then(|h| h.member().map(|m| m.map(str::to_owned))) {
+            Ok(member) => member,
+            Err(_) => continue,
+        };
+
+        match member.as_deref() {
+            Some("ActionInvoked") => {
+                if let Ok((signal_id, action_key)) = message.body::<(u32, String)>() {
+                    if signal_id == id {
+                        invocation_closure(&action_key);
+                        return;
+                    }
+                }
+            }
+            Some("NotificationClosed") => {
+                if let Ok((signal_id, _reason)) = message.body::<(u32, u32)>() {
+                    if signal_id == id {
+                        invocation_closure("__closed");
+                        return;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Async mirror of [`send_notificaion_via_connection`].
+///
+/// Bypasses [`NotificationsProxy`] (it's blocking-only in this zbus version) and calls `Notify`
+/// directly on the connection instead.
+#[cfg(feature = "async")]
+pub async fn send_notificaion_via_connection_async(
+    notification: &Notification,
+    id: u32,
+    connection: &mut zbus::azync::Connection<UnixStream>,
+) -> Result<u32> {
+    let (app_name, id, app_icon, summary, body, actions, expire_timeout) = notify_args(notification, id);
+    let actions: Vec<&str> = actions.iter().map(String::as_str).collect();
+
+    let message = connection
         .call_method(
-            Some(crate::xdg::NOTIFICATION_NAMESPACE),
-            crate::xdg::NOTIFICATION_OBJECTPATH,
-            Some(crate::xdg::NOTIFICATION_NAMESPACE),
+            Some(xdg::NOTIFICATION_NAMESPACE),
+            xdg::NOTIFICATION_OBJECTPATH,
+            Some(xdg::NOTIFICATION_NAMESPACE),
             "Notify",
             &(
-                &notification.appname,
+                app_name,
                 id,
-                &notification.icon,
-                &notification.summary,
-                &notification.body,
-                &notification.actions,
+                app_icon,
+                summary,
+                body,
+                actions,
                 crate::hints::hints_to_map(&notification.hints),
-                notification.timeout.into_i32(),
+                expire_timeout,
             ),
-        )?
-        .body()
-        .unwrap();
-    Ok(dbg!(reply))
+        )
+        .await?;
+    let reply: u32 = message.body().map_err(zbus::Error::from)?;
+    Ok(reply)
 }
 
-pub fn connect_and_send_notification(notification: &Notification) -> Result<ZbusNotificationHandle> {
-    let connection = zbus::Connection::new_session()?;
+/// Async mirror of [`connect_and_send_notification`].
+#[cfg(feature = "async")]
+pub async fn connect_and_send_notification_async(notification: &Notification) -> Result<AsyncNotificationHandle> {
+    let mut connection = zbus::azync::Connection::new_session().await?;
     let inner_id = notification.id.unwrap_or(0);
-    let id = send_notificaion_via_connection(notification, inner_id, &connection)?;
-    Ok(ZbusNotificationHandle::new(id, connection, notification.clone()))
+    let id = send_notificaion_via_connection_async(notification, inner_id, &mut connection).await?;
+    Ok(AsyncNotificationHandle::new(id, connection))
+}
+
+#[cfg(feature = "async")]
+pub async fn close_notification_async(connection: &mut zbus::azync::Connection<UnixStream>, id: u32) -> Result<()> {
+    connection
+        .call_method(
+            Some(xdg::NOTIFICATION_NAMESPACE),
+            xdg::NOTIFICATION_OBJECTPATH,
+            Some(xdg::NOTIFICATION_NAMESPACE),
+            "CloseNotification",
+            &(id,),
+        )
+        .await?;
+    Ok(())
+}
+
+/// Async mirror of [`wait_for_action_signal`].
+#[cfg(feature = "async")]
+async fn wait_for_action_signal_async<F>(connection: &mut zbus::azync::Connection<UnixStream>, id: u32, invocation_closure: F)
+where
+    F: FnOnce(&str),
+{
+    use futures_util::StreamExt;
+
+    let rule = format!("interface='{}'", xdg::NOTIFICATION_NAMESPACE);
+    if connection
+        .call_method(
+            Some("org.freedesktop.DBus"),
+            "/org/freedesktop/DBus",
+            Some("org.freedesktop.DBus"),
+            "AddMatch",
+            &(rule,),
+        )
+        .await
+        .is_err()
+    {
+        return;
+    }
+
+    loop {
+        let message = match connection.next().await {
+            Some(Ok(message)) => message,
+            _ => return,
+        };
+
+        let member = match message.header().and_then(|h| h.member().map(|m| m.map(str::to_owned))) {
+            Ok(member) => member,
+            Err(_) => continue,
+        };
+
+        match member.as_deref() {
+            Some("ActionInvoked") => {
+                if let Ok((signal_id, action_key)) = message.body::<(u32, String)>() {
+                    if signal_id == id {
+                        invocation_closure(&action_key);
+                        return;
+                    }
+                }
+            }
+            Some("NotificationClosed") => {
+                if let Ok((signal_id, _reason)) = message.body::<(u32, u32)>() {
+                    if signal_id == id {
+                        invocation_closure("__closed");
+                        return;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
 }
 
 pub fn get_server_information() -> Result<xdg::ServerInformation> {
     let connection = zbus::Connection::new_session()?;
-    let info: xdg::ServerInformation = connection.call_method(
-            Some(crate::xdg::NOTIFICATION_NAMESPACE),
-            crate::xdg::NOTIFICATION_OBJECTPATH,
-            Some(crate::xdg::NOTIFICATION_NAMESPACE),
-            "GetServerInformation",
-            &()
-        )?.body()
-        .unwrap();
-
-    Ok(info)
-}
\ No newline at end of file
+    let proxy = NotificationsProxy::new(&connection)?;
+    let (name, vendor, version, spec_version) = proxy.get_server_information()?;
+
+    Ok(xdg::ServerInformation {
+        name,
+        vendor,
+        version,
+        spec_version,
+    })
+}
+
+pub fn get_capabilities() -> Result<Vec<String>> {
+    let connection = zbus::Connection::new_session()?;
+    let proxy = NotificationsProxy::new(&connection)?;
+    Ok(proxy.get_capabilities()?)
+}
+
+/// Blocks on `connection` until an action/close signal for `id` arrives.
+///
+/// Used by the free-standing `xdg::handle_action`, which isn't attached to a
+/// [`ZbusNotificationHandle`] and so opens a fresh connection to pass in here.
+pub(crate) fn wait_for_action_on_id<F>(connection: &Connection, id: u32, invocation_closure: F)
+where
+    F: FnOnce(&str),
+{
+    wait_for_action_signal(connection, id, invocation_closure);
+}